@@ -1,5 +1,7 @@
+use std::collections::HashMap;
+
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 
 pub fn derive(input: TokenStream) -> TokenStream {
     derive_impl(input).unwrap_or_else(syn::Error::into_compile_error)
@@ -7,16 +9,103 @@ pub fn derive(input: TokenStream) -> TokenStream {
 
 fn derive_impl(input: TokenStream) -> syn::Result<TokenStream> {
     let input = syn::parse2::<syn::DeriveInput>(input)?;
+    let pairs = display_pairs(&input, "Display")?;
+
+    let (name, inner): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+    let enum_name = input.ident;
+    Ok(quote! {
+        impl ::core::fmt::Display for #enum_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                match self {
+                    #(Self::#name => ::core::write!(f, #inner),)*
+                }
+            }
+        }
+    })
+}
+
+pub fn derive_from_str(input: TokenStream) -> TokenStream {
+    derive_from_str_impl(input).unwrap_or_else(syn::Error::into_compile_error)
+}
+
+fn derive_from_str_impl(input: TokenStream) -> syn::Result<TokenStream> {
+    let input = syn::parse2::<syn::DeriveInput>(input)?;
+    let pairs = display_pairs(&input, "FromStr")?;
 
-    let syn::Data::Enum(data_enum) = input.data else {
+    let literals = pairs
+        .iter()
+        .map(|(variant, inner)| {
+            syn::parse2::<syn::LitStr>(inner.clone()).map_err(|_| {
+                syn::Error::new_spanned(
+                    inner,
+                    format!(
+                        "`#[display(...)]` on variant `{variant}` must be a single string \
+                         literal to derive `FromStr`",
+                    ),
+                )
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let mut seen = HashMap::new();
+    for (literal, (variant, _)) in literals.iter().zip(&pairs) {
+        if let Some(previous) = seen.insert(literal.value(), variant) {
+            return Err(syn::Error::new_spanned(
+                literal,
+                format!(
+                    "display string {:?} is used by both `{previous}` and `{variant}`, so \
+                     `FromStr` would be ambiguous",
+                    literal.value(),
+                ),
+            ));
+        }
+    }
+
+    let (name, _): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+    let enum_name = input.ident;
+    let err_name = format_ident!("{enum_name}FromStrError");
+
+    Ok(quote! {
+        impl ::core::str::FromStr for #enum_name {
+            type Err = #err_name;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                match s {
+                    #(#literals => ::core::result::Result::Ok(Self::#name),)*
+                    other => ::core::result::Result::Err(#err_name(other.to_string())),
+                }
+            }
+        }
+
+        /// The error returned when parsing an unrecognised string with
+        /// [`FromStr`](::core::str::FromStr).
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct #err_name(pub ::std::string::String);
+
+        impl ::core::fmt::Display for #err_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::write!(f, "unrecognised {} string: {:?}", ::core::stringify!(#enum_name), self.0)
+            }
+        }
+
+        impl ::std::error::Error for #err_name {}
+    })
+}
+
+/// Collects each unit variant's name alongside the contents of its
+/// `#[display(...)]` attribute.
+fn display_pairs(
+    input: &syn::DeriveInput,
+    derive_name: &str,
+) -> syn::Result<Vec<(proc_macro2::Ident, TokenStream)>> {
+    let syn::Data::Enum(data_enum) = &input.data else {
         return Err(syn::Error::new(
             Span::call_site(),
-            "`#[derive(Display)]` is only supported on enums",
+            format!("`{derive_name}` is only supported on enums"),
         ));
     };
 
-    // (variant name, #[display(...)] contents)
-    let pairs: Vec<(proc_macro2::Ident, TokenStream)> = data_enum
+    data_enum
         .variants
         .iter()
         .map(|variant| {
@@ -32,17 +121,5 @@ fn derive_impl(input: TokenStream) -> syn::Result<TokenStream> {
 
             Ok((variant.ident.clone(), inner))
         })
-        .collect::<syn::Result<_>>()?;
-
-    let (name, inner): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
-    let enum_name = input.ident;
-    Ok(quote! {
-        impl ::core::fmt::Display for #enum_name {
-            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                match self {
-                    #(Self::#name => ::core::write!(f, #inner),)*
-                }
-            }
-        }
-    })
+        .collect()
 }
@@ -1,51 +1,170 @@
-use proc_macro2::{Span, TokenStream};
-use quote::quote;
+use std::collections::HashMap;
+
+use proc_macro2::{Literal, Span, TokenStream};
+use quote::{format_ident, quote};
 use syn::{punctuated::Punctuated, Token};
 
+/// The `#[repr(..)]` integer types an enum may use with `#[derive(EnumSelect)]`.
+const INTEGER_REPRS: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
 pub fn derive(input: TokenStream) -> TokenStream {
     derive_impl(input).unwrap_or_else(syn::Error::into_compile_error)
 }
 
 fn derive_impl(input: TokenStream) -> syn::Result<TokenStream> {
     let input = syn::parse2(input)?;
-    let UnitEnum { name, variants } = validate_input(input)?;
+    let UnitEnum {
+        name,
+        repr,
+        variants,
+        default_index,
+    } = validate_input(input)?;
+
+    let count = variants.len();
+    let idents: Vec<&syn::Ident> = variants.iter().map(|v| &v.ident).collect();
+    let discriminants: Vec<&TokenStream> = variants.iter().map(|v| &v.discriminant).collect();
+    let ordinals = 0..count;
+
+    let from_index_arms = ordinals.clone().zip(&idents).map(|(ordinal, ident)| {
+        quote! { #ordinal => Self::#ident, }
+    });
+    let to_index_arms = ordinals.zip(&idents).map(|(ordinal, ident)| {
+        quote! { Self::#ident => #ordinal, }
+    });
+    let to_discriminant_arms = idents.iter().zip(&discriminants).map(|(ident, disc)| {
+        quote! { Self::#ident => #disc, }
+    });
+    let try_from_discriminant_arms = variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let disc = &variant.discriminant;
+        let alternatives = &variant.alternatives;
+        quote! {
+            #disc #(| #alternatives)* => ::core::option::Option::Some(Self::#ident),
+        }
+    });
+    // `TryFrom` falls back to the `#[enum_select(default)]` variant (if any)
+    // for unrecognised discriminants; `try_from_discriminant` itself never
+    // does, so it stays a faithful inverse of `to_discriminant`.
+    let default_try_from_fallback = match variants.iter().find(|v| v.is_default) {
+        Some(default) => {
+            let ident = &default.ident;
+            quote! { .or(::core::option::Option::Some(Self::#ident)) }
+        }
+        None => quote! {},
+    };
+
+    let default_index_item = default_index.map(|index| {
+        quote! {
+            const DEFAULT_INDEX: ::core::primitive::usize = #index;
+        }
+    });
+
+    let err_name = format_ident!("{name}TryFromError");
 
     Ok(quote! {
         unsafe impl ::seenum::EnumSelect for #name {
+            type Repr = #repr;
+
             // SAFETY: `count` is non-zero as validated by `validate_input`.
-            const ALL: &'static [Self] = [#(Self::#variants),*].as_slice();
+            const COUNT: ::core::num::NonZeroUsize =
+                unsafe { ::core::num::NonZeroUsize::new_unchecked(#count) };
+
+            #default_index_item
+
+            const ALL: &'static [Self] = [#(Self::#idents),*].as_slice();
 
             unsafe fn from_index_unchecked(index: ::core::primitive::usize) -> Self {
-                // SAFETY: `index` must be between `0..Self::COUNT`.
-                unsafe { ::core::mem::transmute(index) }
+                match index {
+                    #(#from_index_arms)*
+                    // SAFETY: `index` must be between `0..Self::COUNT`.
+                    _ => unsafe { ::core::hint::unreachable_unchecked() },
+                }
+            }
+
+            fn to_index(&self) -> ::core::primitive::usize {
+                match self {
+                    #(#to_index_arms)*
+                }
+            }
+
+            fn to_discriminant(&self) -> Self::Repr {
+                match self {
+                    #(#to_discriminant_arms)*
+                }
+            }
+
+            // an enum's alternatives may happen to be contiguous with its
+            // discriminant, which clippy would rather see as a range pattern
+            #[allow(clippy::manual_range_patterns)]
+            fn try_from_discriminant(value: Self::Repr) -> ::core::option::Option<Self> {
+                match value {
+                    #(#try_from_discriminant_arms)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+
+        impl ::core::convert::From<#name> for #repr {
+            fn from(value: #name) -> Self {
+                <#name as ::seenum::EnumSelect>::to_discriminant(&value)
             }
         }
+
+        impl ::core::convert::TryFrom<#repr> for #name {
+            type Error = #err_name;
+
+            fn try_from(value: #repr) -> ::core::result::Result<Self, Self::Error> {
+                <Self as ::seenum::EnumSelect>::try_from_discriminant(value)
+                    #default_try_from_fallback
+                    .ok_or(#err_name(value))
+            }
+        }
+
+        /// The error returned by the derived `TryFrom` conversion when a
+        /// value doesn't correspond to any variant.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct #err_name(pub #repr);
+
+        impl ::core::fmt::Display for #err_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::write!(
+                    f,
+                    "{:?} is not a valid discriminant for `{}`",
+                    self.0,
+                    ::core::stringify!(#name),
+                )
+            }
+        }
+
+        impl ::std::error::Error for #err_name {}
     })
 }
 
-/// Validates the derive input to be a `#[repr(usize)]` enum with only unit
-/// variants, no custom discriminants and at least one variant.
+/// Validates the derive input to be a `#[repr(/* integer type */)]` enum
+/// with only unit variants and at least one variant, and resolves each
+/// variant's discriminant, default, and alternatives.
 fn validate_input(input: syn::DeriveInput) -> syn::Result<UnitEnum> {
-    // using a loop instead of `iter.any` to return errors if necessary
-    let mut is_repr_usize = false;
-    for attr in input.attrs {
-        if attr.path().is_ident("repr")
-            && attr
-                .parse_args_with(Punctuated::<syn::Ident, Token![,]>::parse_terminated)?
-                .iter()
-                .any(|ident| *ident == "usize")
-        {
-            is_repr_usize = true;
-            break;
+    // using a loop instead of `iter.find_map` to return parse errors
+    let mut repr = None;
+    for attr in &input.attrs {
+        if attr.path().is_ident("repr") {
+            for ident in attr.parse_args_with(Punctuated::<syn::Ident, Token![,]>::parse_terminated)? {
+                if INTEGER_REPRS.contains(&ident.to_string().as_str()) {
+                    repr = Some(ident);
+                    break;
+                }
+            }
         }
     }
 
-    if !is_repr_usize {
+    let Some(repr) = repr else {
         return Err(syn::Error::new(
             Span::call_site(),
-            "enum must have a `#[repr(usize)]`",
+            "enum must have a `#[repr(..)]` with an integer type",
         ));
-    }
+    };
 
     let syn::Data::Enum(data_enum) = input.data else {
         return Err(syn::Error::new(
@@ -58,7 +177,15 @@ fn validate_input(input: syn::DeriveInput) -> syn::Result<UnitEnum> {
         return Err(syn::Error::new(Span::call_site(), "enum must be non-empty"));
     }
 
-    let variants: Vec<proc_macro2::Ident> = data_enum
+    // the discriminant of a variant without an explicit one is the previous
+    // variant's discriminant plus one, starting at 0
+    let mut next_discriminant: i128 = 0;
+    // maps every discriminant value in use (primary or alternative) back to
+    // the variant that claimed it first, to reject overlaps
+    let mut claimed: HashMap<i128, syn::Ident> = HashMap::new();
+    let mut default_variant: Option<syn::Ident> = None;
+
+    let variants = data_enum
         .variants
         .into_iter()
         .map(|variant| {
@@ -72,25 +199,172 @@ fn validate_input(input: syn::DeriveInput) -> syn::Result<UnitEnum> {
                 }
             };
 
-            if let Some(disc) = variant.discriminant {
-                return Err(syn::Error::new_spanned(
-                    disc.1,
-                    "all variants must have the default discriminant",
-                ));
+            let (discriminant, value) = if let Some((_, expr)) = &variant.discriminant {
+                let value = literal_discriminant(expr)?;
+                next_discriminant = value;
+                (quote!(#expr), value)
+            } else {
+                let value = next_discriminant;
+                (unsuffixed_int_literal(value), value)
+            };
+            next_discriminant += 1;
+            claim_discriminant(&mut claimed, value, &variant.ident)?;
+
+            let EnumSelectAttr {
+                is_default,
+                alternatives,
+            } = parse_enum_select_attr(&variant.attrs)?;
+
+            if is_default {
+                if let Some(previous) = &default_variant {
+                    return Err(syn::Error::new_spanned(
+                        &variant.ident,
+                        format!(
+                            "only one variant can be `#[enum_select(default)]`, \
+                             `{previous}` is already marked as the default",
+                        ),
+                    ));
+                }
+                default_variant = Some(variant.ident.clone());
             }
 
-            Ok(variant.ident)
+            let alternatives = alternatives
+                .into_iter()
+                .map(|expr| {
+                    let value = literal_discriminant(&expr)?;
+                    claim_discriminant(&mut claimed, value, &variant.ident)?;
+                    Ok(quote!(#expr))
+                })
+                .collect::<syn::Result<_>>()?;
+
+            Ok(Variant {
+                ident: variant.ident,
+                discriminant,
+                alternatives,
+                is_default,
+            })
         })
-        .collect::<syn::Result<_>>()?;
+        .collect::<syn::Result<Vec<Variant>>>()?;
+
+    let default_index = default_variant
+        .map(|default| {
+            variants
+                .iter()
+                .position(|v| v.ident == default)
+                .expect("default variant should be in `variants`")
+        });
 
     Ok(UnitEnum {
         name: input.ident,
+        repr,
         variants,
+        default_index,
     })
 }
 
-/// An enum with only unit variants.
+/// Records that `value` is used by `owner`, returning an error if another
+/// variant has already claimed it as a discriminant or alternative.
+fn claim_discriminant(
+    claimed: &mut HashMap<i128, syn::Ident>,
+    value: i128,
+    owner: &syn::Ident,
+) -> syn::Result<()> {
+    if let Some(previous) = claimed.insert(value, owner.clone()) {
+        return Err(syn::Error::new_spanned(
+            owner,
+            format!("discriminant value {value} is already used by `{previous}`"),
+        ));
+    }
+    Ok(())
+}
+
+/// Evaluates a variant discriminant expression, which must be an integer
+/// literal (optionally negated).
+fn literal_discriminant(expr: &syn::Expr) -> syn::Result<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(int),
+            ..
+        }) => int.base10_parse(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => Ok(-literal_discriminant(expr)?),
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            "variant discriminant must be an integer literal",
+        )),
+    }
+}
+
+/// Emits an integer literal with no type suffix, so it infers whatever
+/// integer type is expected (the enum's `Repr`), unlike quoting an `i128`
+/// directly which would always emit an `i128`-suffixed literal.
+fn unsuffixed_int_literal(value: i128) -> TokenStream {
+    TokenStream::from(proc_macro2::TokenTree::Literal(Literal::i128_unsuffixed(
+        value,
+    )))
+}
+
+/// Parses a variant's `#[enum_select(default)]` and
+/// `#[enum_select(alternatives = [..])]` attributes.
+fn parse_enum_select_attr(attrs: &[syn::Attribute]) -> syn::Result<EnumSelectAttr> {
+    let mut result = EnumSelectAttr::default();
+
+    for attr in attrs {
+        if !attr.path().is_ident("enum_select") {
+            continue;
+        }
+
+        for meta in attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)? {
+            if meta.path().is_ident("default") {
+                result.is_default = true;
+            } else if meta.path().is_ident("alternatives") {
+                let syn::Meta::NameValue(syn::MetaNameValue {
+                    value: syn::Expr::Array(array),
+                    ..
+                }) = &meta
+                else {
+                    return Err(syn::Error::new_spanned(
+                        &meta,
+                        "`alternatives` must be a list, e.g. `alternatives = [2, 3]`",
+                    ));
+                };
+                result.alternatives.extend(array.elems.iter().cloned());
+            } else {
+                return Err(syn::Error::new_spanned(
+                    meta.path(),
+                    "unknown `#[enum_select(..)]` attribute, expected `default` or `alternatives`",
+                ));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[derive(Default)]
+struct EnumSelectAttr {
+    is_default: bool,
+    alternatives: Vec<syn::Expr>,
+}
+
+/// An enum with only unit variants, with each variant's resolved
+/// discriminant.
 struct UnitEnum {
     name: syn::Ident,
-    variants: Vec<proc_macro2::Ident>,
+    repr: syn::Ident,
+    variants: Vec<Variant>,
+    /// The ordinal of the `#[enum_select(default)]` variant, if any.
+    default_index: Option<usize>,
+}
+
+struct Variant {
+    ident: syn::Ident,
+    /// The resolved discriminant, as a literal token.
+    discriminant: TokenStream,
+    /// Extra discriminant values that also map to this variant.
+    alternatives: Vec<TokenStream>,
+    is_default: bool,
 }
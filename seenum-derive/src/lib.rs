@@ -2,7 +2,7 @@
 
 use proc_macro::TokenStream;
 
-#[proc_macro_derive(EnumSelect)]
+#[proc_macro_derive(EnumSelect, attributes(enum_select))]
 pub fn derive_enum_select(input: TokenStream) -> TokenStream {
     seenum_core::enum_select::derive(input.into()).into()
 }
@@ -11,3 +11,8 @@ pub fn derive_enum_select(input: TokenStream) -> TokenStream {
 pub fn derive_display(input: TokenStream) -> TokenStream {
     seenum_core::display::derive(input.into()).into()
 }
+
+#[proc_macro_derive(FromStr, attributes(display))]
+pub fn derive_from_str(input: TokenStream) -> TokenStream {
+    seenum_core::display::derive_from_str(input.into()).into()
+}
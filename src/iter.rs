@@ -0,0 +1,102 @@
+use std::marker::PhantomData;
+
+use crate::EnumSelect;
+
+/// A double-ended, exact-size iterator over a contiguous ordinal range of an
+/// [`EnumSelect`] enum's variants.
+///
+/// Returned by [`EnumSelect::iter_from`] and [`EnumSelect::range`]; see
+/// [`EnumSelect::iter_cycle`] for an indefinitely-repeating variant built on
+/// top of this iterator.
+pub struct EnumSelectIter<T: EnumSelect> {
+    /// Ordinal of the next item to yield from the front.
+    front: usize,
+    /// Ordinal one past the next item to yield from the back (exclusive).
+    back: usize,
+    step: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: EnumSelect> EnumSelectIter<T> {
+    pub(crate) fn bounded(front: usize, back: usize) -> Self {
+        assert!(
+            front <= back,
+            "range start ({front}) must not be after its end ({back})"
+        );
+        Self {
+            front,
+            back,
+            step: 1,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Skips `n - 1` variants between each item yielded by the iterator,
+    /// similar to [`Iterator::step_by`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    #[must_use]
+    pub fn step(mut self, n: usize) -> Self {
+        assert_ne!(n, 0, "step size must not be zero");
+        self.step = n;
+        self
+    }
+
+    fn variant_at(ordinal: usize) -> T {
+        // reduced modulo `COUNT` so that a rotated range (as used by
+        // `iter_cycle`) still maps onto a real variant
+        T::try_from_index(ordinal % T::COUNT.get())
+            .expect("ordinal modulo COUNT should be within range")
+    }
+}
+
+impl<T: EnumSelect> Clone for EnumSelectIter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            front: self.front,
+            back: self.back,
+            step: self.step,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: EnumSelect> Iterator for EnumSelectIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.front >= self.back {
+            return None;
+        }
+        let ordinal = self.front;
+        self.front = (self.front + self.step).min(self.back);
+        Some(Self::variant_at(ordinal))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: EnumSelect> DoubleEndedIterator for EnumSelectIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front >= self.back {
+            return None;
+        }
+        // align `back` to the last element actually reachable from `front`
+        // when stepping by `self.step`
+        let steps_from_front = (self.back - self.front - 1) / self.step;
+        let ordinal = self.front + steps_from_front * self.step;
+        self.back = ordinal;
+        Some(Self::variant_at(ordinal))
+    }
+}
+
+impl<T: EnumSelect> ExactSizeIterator for EnumSelectIter<T> {
+    fn len(&self) -> usize {
+        self.back.saturating_sub(self.front).div_ceil(self.step)
+    }
+}
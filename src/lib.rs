@@ -3,7 +3,10 @@
 
 use std::num::NonZeroUsize;
 
-pub use seenum_derive::{Display, EnumSelect};
+mod iter;
+
+pub use iter::EnumSelectIter;
+pub use seenum_derive::{Display, EnumSelect, FromStr};
 
 /// An enum trait for traversing through its variants.
 ///
@@ -17,18 +20,36 @@ pub use seenum_derive::{Display, EnumSelect};
 /// # Safety
 ///
 /// This trait must only be implemented on enums that:
-/// - Are `#[repr(usize)]`
+/// - Have a `#[repr(/* integer type */)]` matching [`Repr`](EnumSelect::Repr)
 /// - Only contain unit variants (no tuple or named fields)
 /// - Have at least one variant
-/// - All variants have the default discriminant, so that discriminants
-///   in the range `0..Self::COUNT` are all defined.
 pub unsafe trait EnumSelect
 where
     Self: Sized + 'static,
 {
+    /// The integer type backing this enum's `#[repr(..)]`, used for its
+    /// discriminants.
+    ///
+    /// This is independent from the *ordinal* index used by [`to_index`]
+    /// and [`try_from_index`], which is always a dense `0..Self::COUNT`
+    /// regardless of what discriminants the variants have.
+    ///
+    /// [`to_index`]: EnumSelect::to_index
+    /// [`try_from_index`]: EnumSelect::try_from_index
+    type Repr: Copy + Eq;
+
     /// The number of variants in the enum.
     const COUNT: NonZeroUsize;
 
+    /// The ordinal index of the variant used as a fallback by
+    /// [`from_index_or_default`] and the derived `TryFrom` conversion, when
+    /// a variant is marked with `#[enum_select(default)]`.
+    ///
+    /// Defaults to `0` (the first variant) if no variant is marked.
+    ///
+    /// [`from_index_or_default`]: EnumSelect::from_index_or_default
+    const DEFAULT_INDEX: usize = 0;
+
     /// All variants as a slice, in order from first to last.
     ///
     /// # Examples
@@ -57,9 +78,9 @@ where
     /// # Safety
     /// The trait conditions must be met, as well as having `index` be in the
     /// the range `0..Self::COUNT` (not including `COUNT`).
-
-    // This method can't have a default implementation as the size is unknown,
-    // `std::mem::transmute` doesn't compile.
+    // This method can't have a default implementation as the trait doesn't
+    // know the enum's variants; the derive macro generates it as a match
+    // over the ordinal index.
     #[must_use]
     unsafe fn from_index_unchecked(index: usize) -> Self;
 
@@ -76,7 +97,24 @@ where
         }
     }
 
-    /// Converts an enum to its index discriminant.
+    /// Converts an index discriminant to an enum variant, falling back to
+    /// [`DEFAULT_INDEX`] if the index is not within `0..Self::COUNT`.
+    ///
+    /// [`DEFAULT_INDEX`]: EnumSelect::DEFAULT_INDEX
+    #[must_use]
+    fn from_index_or_default(index: usize) -> Self {
+        Self::try_from_index(index).unwrap_or_else(|| {
+            Self::try_from_index(Self::DEFAULT_INDEX)
+                .expect("DEFAULT_INDEX should be within range 0..Self::COUNT")
+        })
+    }
+
+    /// Converts an enum to its ordinal index, in the range `0..Self::COUNT`.
+    ///
+    /// This is the position of the variant in the enum definition, not its
+    /// discriminant; use [`to_discriminant`] for the latter.
+    ///
+    /// [`to_discriminant`]: EnumSelect::to_discriminant
     ///
     /// # Examples
     ///
@@ -90,14 +128,52 @@ where
     ///
     /// assert_eq!(Note::C.to_index(), 2);
     /// ```
-    fn to_index(&self) -> usize {
-        // https://doc.rust-lang.org/stable/reference/items/enumerations.html#pointer-casting
-        // SAFETY: the enum has a #[repr(usize)]
-        #[allow(clippy::ptr_as_ptr)]
-        unsafe {
-            *(self as *const Self as *const usize)
-        }
-    }
+    #[must_use]
+    fn to_index(&self) -> usize;
+
+    /// Converts an enum to its `#[repr(..)]` discriminant.
+    ///
+    /// Unlike [`to_index`], this reflects any explicit discriminants on the
+    /// enum's variants, so it may not be contiguous.
+    ///
+    /// [`to_index`]: EnumSelect::to_index
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use seenum::EnumSelect;
+    /// #[derive(Debug, PartialEq, Eq, EnumSelect)]
+    /// #[repr(u16)]
+    /// enum Status {
+    ///     Ok = 200,
+    ///     NotFound = 404,
+    /// }
+    ///
+    /// assert_eq!(Status::NotFound.to_discriminant(), 404);
+    /// ```
+    #[must_use]
+    fn to_discriminant(&self) -> Self::Repr;
+
+    /// Converts a `#[repr(..)]` discriminant to an enum variant.
+    ///
+    /// If no variant has this discriminant, [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use seenum::EnumSelect;
+    /// #[derive(Debug, PartialEq, Eq, EnumSelect)]
+    /// #[repr(u16)]
+    /// enum Status {
+    ///     Ok = 200,
+    ///     NotFound = 404,
+    /// }
+    ///
+    /// assert_eq!(Status::try_from_discriminant(404), Some(Status::NotFound));
+    /// assert_eq!(Status::try_from_discriminant(500), None);
+    /// ```
+    #[must_use]
+    fn try_from_discriminant(value: Self::Repr) -> Option<Self>;
 
     /// Gets the first variant.
     ///
@@ -285,4 +361,190 @@ where
     fn saturating_prev(&self) -> Self {
         self.checked_prev().unwrap_or_else(Self::first)
     }
+
+    /// Returns the signed ordinal distance from `self` to `other`: positive
+    /// if `other` comes after `self`, negative if it comes before.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use seenum::EnumSelect;
+    /// #[derive(Debug, PartialEq, Eq, EnumSelect)]
+    /// #[repr(usize)]
+    /// enum Note {
+    ///     A, B, C, D, E, F, G
+    /// }
+    ///
+    /// assert_eq!(Note::B.steps_between(&Note::E), 3);
+    /// assert_eq!(Note::E.steps_between(&Note::B), -3);
+    /// ```
+    #[must_use]
+    fn steps_between(&self, other: &Self) -> isize {
+        // indices never get close to `isize::MAX`, an enum with that many
+        // variants wouldn't exist in practice
+        #[allow(clippy::cast_possible_wrap)]
+        {
+            other.to_index() as isize - self.to_index() as isize
+        }
+    }
+
+    /// Returns the variant `n` positions after `self` (or before, if `n` is
+    /// negative), or [`None`] if that would go out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use seenum::EnumSelect;
+    /// #[derive(Debug, PartialEq, Eq, EnumSelect)]
+    /// #[repr(usize)]
+    /// enum Note {
+    ///     A, B, C, D, E, F, G
+    /// }
+    ///
+    /// assert_eq!(Note::B.checked_add(3), Some(Note::E));
+    /// assert_eq!(Note::B.checked_add(-5), None);
+    /// ```
+    #[must_use = "returns a new instance instead of modifying its argument"]
+    fn checked_add(&self, n: isize) -> Option<Self> {
+        #[allow(clippy::cast_possible_wrap)]
+        let index = (self.to_index() as isize).checked_add(n)?;
+        usize::try_from(index).ok().and_then(Self::try_from_index)
+    }
+
+    /// Returns the variant `n` positions after `self` (or before, if `n` is
+    /// negative), wrapping around the ends of the enum as many times as
+    /// necessary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use seenum::EnumSelect;
+    /// #[derive(Debug, PartialEq, Eq, EnumSelect)]
+    /// #[repr(usize)]
+    /// enum Note {
+    ///     A, B, C, D, E, F, G
+    /// }
+    ///
+    /// assert_eq!(Note::F.wrapping_add(3), Note::B);
+    /// assert_eq!(Note::B.wrapping_add(-5), Note::D);
+    /// ```
+    #[must_use = "returns a new instance instead of modifying its argument"]
+    fn wrapping_add(&self, n: isize) -> Self {
+        #[allow(clippy::cast_possible_wrap)]
+        let count = Self::COUNT.get() as isize;
+        // reduce `n` modulo `count` first, so the following add can't
+        // overflow `isize` even if `n` is near `isize::MIN`/`isize::MAX`
+        let offset = n.rem_euclid(count);
+        #[allow(clippy::cast_possible_wrap)]
+        let index = (self.to_index() as isize + offset) % count;
+        // `offset` is within `0..count`, so `index` is too
+        #[allow(clippy::cast_sign_loss)]
+        Self::try_from_index(index as usize).expect("index should be within range")
+    }
+
+    /// Returns the variant `n` positions after `self` (or before, if `n` is
+    /// negative), saturating at [`first`]/[`last`] if that would go out of
+    /// range.
+    ///
+    /// [`first`]: EnumSelect::first
+    /// [`last`]: EnumSelect::last
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use seenum::EnumSelect;
+    /// #[derive(Debug, PartialEq, Eq, EnumSelect)]
+    /// #[repr(usize)]
+    /// enum Note {
+    ///     A, B, C, D, E, F, G
+    /// }
+    ///
+    /// assert_eq!(Note::F.saturating_add(10), Note::G);
+    /// assert_eq!(Note::B.saturating_add(-10), Note::A);
+    /// ```
+    #[must_use = "returns a new instance instead of modifying its argument"]
+    fn saturating_add(&self, n: isize) -> Self {
+        self.checked_add(n).unwrap_or_else(|| {
+            if n < 0 {
+                Self::first()
+            } else {
+                Self::last()
+            }
+        })
+    }
+
+    /// Returns an iterator over the variants from `self` (inclusive) to the
+    /// last variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use seenum::EnumSelect;
+    /// #[derive(Debug, PartialEq, Eq, EnumSelect)]
+    /// #[repr(usize)]
+    /// enum Note {
+    ///     A, B, C, D, E, F, G
+    /// }
+    ///
+    /// let from_c: Vec<_> = Note::C.iter_from().collect();
+    /// assert_eq!(from_c, [Note::C, Note::D, Note::E, Note::F, Note::G]);
+    /// ```
+    #[must_use]
+    fn iter_from(&self) -> EnumSelectIter<Self> {
+        EnumSelectIter::bounded(self.to_index(), Self::COUNT.get())
+    }
+
+    /// Returns an iterator that cycles through every variant indefinitely,
+    /// starting from `self` and wrapping back to the first variant after
+    /// the last, in the same order as [`wrapping_next`].
+    ///
+    /// [`wrapping_next`]: EnumSelect::wrapping_next
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use seenum::EnumSelect;
+    /// #[derive(Debug, PartialEq, Eq, EnumSelect)]
+    /// #[repr(usize)]
+    /// enum Note {
+    ///     A, B, C, D, E, F, G
+    /// }
+    ///
+    /// let cycled: Vec<_> = Note::F.iter_cycle().take(4).collect();
+    /// assert_eq!(cycled, [Note::F, Note::G, Note::A, Note::B]);
+    /// ```
+    fn iter_cycle(&self) -> std::iter::Cycle<EnumSelectIter<Self>> {
+        let start = self.to_index();
+        EnumSelectIter::bounded(start, start + Self::COUNT.get()).cycle()
+    }
+
+    /// Returns an iterator over the variants with ordinal index in
+    /// `start..end`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start > end` or `end > Self::COUNT`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use seenum::EnumSelect;
+    /// #[derive(Debug, PartialEq, Eq, EnumSelect)]
+    /// #[repr(usize)]
+    /// enum Note {
+    ///     A, B, C, D, E, F, G
+    /// }
+    ///
+    /// let middle: Vec<_> = Note::range(2, 5).collect();
+    /// assert_eq!(middle, [Note::C, Note::D, Note::E]);
+    /// ```
+    #[must_use]
+    fn range(start: usize, end: usize) -> EnumSelectIter<Self> {
+        assert!(
+            end <= Self::COUNT.get(),
+            "range end ({end}) is out of bounds for `Self::COUNT` ({})",
+            Self::COUNT
+        );
+        EnumSelectIter::bounded(start, end)
+    }
 }
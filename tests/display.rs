@@ -1,8 +1,10 @@
 #![allow(dead_code)]
 
-use seenum::{Display, EnumSelect};
+use std::str::FromStr;
 
-#[derive(Debug, Display, EnumSelect)]
+use seenum::{Display, EnumSelect, FromStr};
+
+#[derive(Debug, PartialEq, Display, FromStr, EnumSelect)]
 #[repr(usize)]
 enum DurationType {
     #[display("1 minute")]
@@ -26,3 +28,16 @@ fn display() {
         ["1 minute", "5 minutes", "50 words", "100 words", "Endless"]
     );
 }
+
+#[test]
+fn from_str_round_trips_display() {
+    for variant in DurationType::ALL {
+        let parsed = DurationType::from_str(&variant.to_string()).unwrap();
+        assert_eq!(parsed, *variant);
+    }
+}
+
+#[test]
+fn from_str_rejects_unknown() {
+    assert!(DurationType::from_str("unknown").is_err());
+}
@@ -0,0 +1,48 @@
+#![allow(dead_code)]
+
+use seenum::EnumSelect;
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumSelect)]
+#[repr(usize)]
+enum Note {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+}
+
+#[test]
+fn iter_from() {
+    let mut iter = Note::C.iter_from();
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.next(), Some(Note::C));
+    assert_eq!(iter.next_back(), Some(Note::G));
+    assert_eq!(iter.collect::<Vec<_>>(), [Note::D, Note::E, Note::F]);
+}
+
+#[test]
+fn iter_cycle() {
+    let cycled: Vec<_> = Note::F.iter_cycle().take(5).collect();
+    assert_eq!(cycled, [Note::F, Note::G, Note::A, Note::B, Note::C]);
+}
+
+#[test]
+fn range() {
+    let middle: Vec<_> = Note::range(2, 5).collect();
+    assert_eq!(middle, [Note::C, Note::D, Note::E]);
+}
+
+#[test]
+fn step() {
+    let stepped: Vec<_> = Note::range(0, 7).step(2).collect();
+    assert_eq!(stepped, [Note::A, Note::C, Note::E, Note::G]);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn range_out_of_bounds_panics() {
+    let _ = Note::range(0, 8);
+}
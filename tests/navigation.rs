@@ -0,0 +1,44 @@
+#![allow(dead_code)]
+
+use seenum::EnumSelect;
+
+#[derive(Debug, PartialEq, EnumSelect)]
+#[repr(usize)]
+enum Note {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+}
+
+#[test]
+fn steps_between() {
+    assert_eq!(Note::B.steps_between(&Note::E), 3);
+    assert_eq!(Note::E.steps_between(&Note::B), -3);
+    assert_eq!(Note::A.steps_between(&Note::A), 0);
+}
+
+#[test]
+fn checked_add() {
+    assert_eq!(Note::B.checked_add(3), Some(Note::E));
+    assert_eq!(Note::B.checked_add(-1), Some(Note::A));
+    assert_eq!(Note::B.checked_add(-5), None);
+    assert_eq!(Note::F.checked_add(5), None);
+}
+
+#[test]
+fn wrapping_add() {
+    assert_eq!(Note::F.wrapping_add(3), Note::B);
+    assert_eq!(Note::B.wrapping_add(-5), Note::D);
+    assert_eq!(Note::A.wrapping_add(7), Note::A);
+}
+
+#[test]
+fn saturating_add() {
+    assert_eq!(Note::F.saturating_add(10), Note::G);
+    assert_eq!(Note::B.saturating_add(-10), Note::A);
+    assert_eq!(Note::C.saturating_add(1), Note::D);
+}
@@ -0,0 +1,45 @@
+#![allow(dead_code)]
+
+use seenum::EnumSelect;
+
+#[derive(Debug, PartialEq, EnumSelect)]
+#[repr(u16)]
+enum Status {
+    Ok = 200,
+    #[enum_select(alternatives = [301, 302])]
+    Redirect = 300,
+    #[enum_select(default)]
+    NotFound = 404,
+}
+
+#[test]
+fn into_repr() {
+    assert_eq!(u16::from(Status::Ok), 200);
+    assert_eq!(u16::from(Status::Redirect), 300);
+    assert_eq!(u16::from(Status::NotFound), 404);
+}
+
+#[test]
+fn try_from_repr() {
+    assert_eq!(Status::try_from(200), Ok(Status::Ok));
+    assert_eq!(Status::try_from(301), Ok(Status::Redirect));
+    assert_eq!(Status::try_from(302), Ok(Status::Redirect));
+    // no variant claims 500, but `NotFound` is the default so it is returned
+    // instead of an error
+    assert_eq!(Status::try_from(500), Ok(Status::NotFound));
+}
+
+#[test]
+fn try_from_discriminant_does_not_fall_back_to_default() {
+    assert_eq!(Status::try_from_discriminant(404), Some(Status::NotFound));
+    // unlike `TryFrom`, `try_from_discriminant` never falls back to the
+    // default variant for an unrecognised discriminant
+    assert_eq!(Status::try_from_discriminant(500), None);
+}
+
+#[test]
+fn from_index_or_default() {
+    assert_eq!(Status::from_index_or_default(0), Status::Ok);
+    // out of range, falls back to the `#[enum_select(default)]` variant
+    assert_eq!(Status::from_index_or_default(10), Status::NotFound);
+}
@@ -0,0 +1,11 @@
+use seenum::FromStr;
+
+#[derive(FromStr)]
+pub enum Thing {
+    #[display("same")]
+    A,
+    #[display("same")]
+    B,
+}
+
+fn main() {}
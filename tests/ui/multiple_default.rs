@@ -0,0 +1,12 @@
+use seenum::EnumSelect;
+
+#[derive(EnumSelect)]
+#[repr(u8)]
+pub enum Thing {
+    #[enum_select(default)]
+    A,
+    #[enum_select(default)]
+    B,
+}
+
+fn main() {}